@@ -24,16 +24,75 @@
 use std::error::Error;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::ops::{Deref, DerefMut};
+use std::ptr;
 use std::sync::{Arc, Condvar, Mutex, Weak};
-use std::time::{Duration, SystemTime};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+#[cfg(feature = "reaper")]
+use std::thread;
+
+#[cfg(feature = "async")]
+use std::collections::VecDeque;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+
+/// Number of low bits of the versioned stack head reserved for the node
+/// address. 48 bits covers the usable virtual-address width of mainstream
+/// 64-bit targets (x86-64 4-level paging, aarch64 48-bit VA); the remaining
+/// high bits hold the ABA tag. This is a hard platform restriction: on
+/// configurations with wider user pointers (x86-64 5-level paging / 57-bit VA,
+/// aarch64 52-bit VA) a node address would not fit: `push_node` checks this
+/// with a `debug_assert!`, so debug builds panic while release builds truncate
+/// the address silently.
+const TREIBER_PTR_BITS: usize = 48;
+const TREIBER_PTR_MASK: usize = (1 << TREIBER_PTR_BITS) - 1;
 
 pub struct Pool<T> {
     constructor: Box<Fn() -> T + Send + Sync + 'static>,
-    items: Mutex<Items<T>>,
+    check: Option<Box<Fn(&T) -> bool + Send + Sync + 'static>>,
+    recycle: Option<Box<Fn(&mut T) + Send + Sync + 'static>>,
+    max_idle: Option<Duration>,
+    max_lifetime: Option<Duration>,
+    sized_constructor: Option<Arc<Fn(usize) -> T + Send + Sync + 'static>>,
+    buckets: Option<Mutex<Vec<Bucket<T>>>>,
+    bucket_available: Condvar,
+    max: Option<usize>,
+    /// Lock-free Treiber stack of available items; the hot acquire/release path
+    /// never touches `wait`. The head is a *versioned* pointer — the node
+    /// address in the low [`TREIBER_PTR_BITS`] bits and a monotonically bumped
+    /// tag in the high bits — so a pop cannot succeed against a reused address
+    /// (the ABA problem): any intervening push/pop changes the tag and fails
+    /// the compare-exchange.
+    available: AtomicUsize,
+    /// Number of nodes currently on the Treiber stack, maintained on every
+    /// push/pop. Reading it is the sound way to observe the free-list length —
+    /// walking the `next` chain would race a concurrent `pop_node`/drop.
+    available_count: AtomicUsize,
+    count: AtomicUsize,
+    outstanding: AtomicUsize,
+    high_water: AtomicUsize,
+    total_acquired: AtomicUsize,
+    watermarks: Option<Watermarks>,
+    wait: Mutex<Wait>,
     item_available: Condvar,
+    /// Number of parked waiters — sync threads blocked on `item_available`
+    /// plus registered async wakers. Lets the release path skip the `wait`
+    /// mutex entirely when nobody is waiting, keeping the hot path lock-free.
+    waiter_count: AtomicUsize,
     weak_self: Weak<Pool<T>>,
 }
 
+// The free list only ever transfers ownership of `T` between threads (a popped
+// node is handed out exclusively, never shared), so `T: Send` is sufficient for
+// the pool to be shared, matching the bound the old `Mutex<Vec<T>>` implied.
+unsafe impl<T: Send> Send for Pool<T> {}
+unsafe impl<T: Send> Sync for Pool<T> {}
+
 impl<T> Pool<T> {
     pub fn new<C>(constructor: C) -> Arc<Pool<T>>
         where C: Fn() -> T + Send + Sync + 'static {
@@ -44,94 +103,881 @@ impl<T> Pool<T> {
     pub fn with_capacity<C>(capacity: usize, constructor: C) -> Arc<Pool<T>>
         where C: Fn() -> T + Send + Sync + 'static {
 
+        Pool::builder(constructor).capacity(capacity).build()
+    }
+
+    /// Creates a pool with `capacity` as its upper bound and `prefill` items
+    /// constructed up front.
+    ///
+    /// Unlike the lazy [`new`](Pool::new)/[`with_capacity`](Pool::with_capacity)
+    /// constructors, this pays construction cost eagerly so latency-sensitive
+    /// callers do not absorb it on the first `get()` calls under load.
+    pub fn with_prefilled<C>(capacity: usize, prefill: usize, constructor: C) -> Arc<Pool<T>>
+        where C: Fn() -> T + Send + Sync + 'static {
+
+        // Never construct past the capacity the pool will enforce; a larger
+        // `prefill` would leave `count` permanently above `max`.
+        let prefill = prefill.min(capacity);
+        let pool = Pool::builder(constructor).capacity(capacity).build();
+        for _ in 0..prefill {
+            let value = (pool.constructor)();
+            let now = Instant::now();
+            pool.push_node(Box::new(Node {
+                pooled: Pooled { value, created: now, returned: now },
+                next: ptr::null_mut(),
+            }));
+        }
+        pool.count.store(prefill, Ordering::Release);
+        pool
+    }
+
+    /// Creates a size-classed pool from a set of `(block_size, count)` buckets.
+    ///
+    /// Items are grouped into buckets ordered by `block_size`; each bucket
+    /// holds at most `count` live items constructed by `constructor`, which is
+    /// passed the bucket's `block_size`. Acquire items with
+    /// [`get_sized`](Pool::get_sized). The plain [`get`](Pool::get) still works
+    /// and behaves like `get_sized(0)`: it draws from the smallest class and
+    /// spills up into larger ones when that class is full.
+    pub fn size_classed<C>(classes: Vec<(usize, usize)>, constructor: C) -> Arc<Pool<T>>
+        where T: 'static, C: Fn(usize) -> T + Send + Sync + 'static {
+
+        let mut classes = classes;
+        classes.sort_by_key(|&(block_size, _)| block_size);
+        let buckets: Vec<Bucket<T>> = classes.iter()
+            .map(|&(block_size, max)| Bucket { block_size, available: Vec::new(), count: 0, max })
+            .collect();
+        let smallest = classes.first().map(|&(block_size, _)| block_size).unwrap_or(0);
+
+        let sized_constructor: Arc<Fn(usize) -> T + Send + Sync + 'static> = Arc::new(constructor);
+        let default_constructor = sized_constructor.clone();
+
         let pool = Arc::new(Pool {
+            constructor: Box::new(move || (*default_constructor)(smallest)),
+            check: None,
+            recycle: None,
+            max_idle: None,
+            max_lifetime: None,
+            sized_constructor: Some(sized_constructor),
+            buckets: Some(Mutex::new(buckets)),
+            bucket_available: Condvar::new(),
+            max: Some(std::usize::MAX),
+            available: AtomicUsize::new(0),
+            available_count: AtomicUsize::new(0),
+            count: AtomicUsize::new(0),
+            outstanding: AtomicUsize::new(0),
+            high_water: AtomicUsize::new(0),
+            total_acquired: AtomicUsize::new(0),
+            watermarks: None,
+            wait: Mutex::new(Wait::new()),
+            item_available: Condvar::new(),
+            waiter_count: AtomicUsize::new(0),
+            weak_self: Weak::new(),
+        });
+        unsafe {
+            let weak = &pool.weak_self as *const Weak<Pool<T>> as *mut Weak<Pool<T>>;
+            *weak = Arc::downgrade(&pool);
+        }
+        pool
+    }
+
+    /// Acquires an item from the smallest size class whose `block_size` is at
+    /// least `min_len`.
+    ///
+    /// When the best-fit bucket is full the request spills up into the next
+    /// larger bucket rather than blocking, so the pool stays usable for any
+    /// size as long as some large-enough bucket has room. If every bucket from
+    /// the best fit up is full, this blocks until an item is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pool was not created with [`size_classed`](Pool::size_classed)
+    /// or if no size class is large enough for `min_len`.
+    pub fn get_sized(&self, min_len: usize) -> Item<T> {
+        let buckets_mutex = self.buckets.as_ref()
+            .expect("pool was not configured with size classes");
+        let mut buckets = buckets_mutex.lock().unwrap();
+
+        loop {
+            let start = buckets.iter().position(|bucket| bucket.block_size >= min_len)
+                .expect("no size class large enough for request");
+
+            for index in start..buckets.len() {
+                if let Some(value) = buckets[index].available.pop() {
+                    return self.wrap_sized(value, index);
+                }
+                if buckets[index].count < buckets[index].max {
+                    buckets[index].count += 1;
+                    // Keep the global `count` in step with the per-bucket counts
+                    // so `count()`/`stats()` observe live sized items too.
+                    self.count.fetch_add(1, Ordering::AcqRel);
+                    let block_size = buckets[index].block_size;
+                    drop(buckets);
+                    let constructor = self.sized_constructor.as_ref().unwrap();
+                    return self.wrap_sized((**constructor)(block_size), index);
+                }
+            }
+
+            buckets = self.bucket_available.wait(buckets).unwrap();
+        }
+    }
+
+    /// Starts building a pool with optional `check` and `recycle` hooks.
+    ///
+    /// The `check` hook is run on an item popped from the free list; if it
+    /// returns `false` the item is dropped and another is acquired, so broken
+    /// resources are never handed out. The `recycle` hook is run when an item
+    /// is returned, giving each borrow a clean starting state.
+    pub fn builder<C>(constructor: C) -> Builder<T>
+        where C: Fn() -> T + Send + Sync + 'static {
+
+        Builder {
             constructor: Box::new(constructor),
-            items: Mutex::new(Items {
-                available: Vec::new(),
-                count: 0,
-                max: Some(capacity),
-            }),
+            check: None,
+            recycle: None,
+            max_idle: None,
+            max_lifetime: None,
+            watermarks: None,
+            capacity: std::usize::MAX,
+        }
+    }
+
+    fn from_builder(builder: Builder<T>) -> Arc<Pool<T>> {
+        let pool = Arc::new(Pool {
+            constructor: builder.constructor,
+            check: builder.check,
+            recycle: builder.recycle,
+            max_idle: builder.max_idle,
+            max_lifetime: builder.max_lifetime,
+            sized_constructor: None,
+            buckets: None,
+            bucket_available: Condvar::new(),
+            max: Some(builder.capacity),
+            available: AtomicUsize::new(0),
+            available_count: AtomicUsize::new(0),
+            count: AtomicUsize::new(0),
+            outstanding: AtomicUsize::new(0),
+            high_water: AtomicUsize::new(0),
+            total_acquired: AtomicUsize::new(0),
+            watermarks: builder.watermarks,
+            wait: Mutex::new(Wait::new()),
             item_available: Condvar::new(),
+            waiter_count: AtomicUsize::new(0),
             weak_self: Weak::new(),
         });
         unsafe {
-            let ptr = &pool.weak_self as *const Weak<Pool<T>> as *mut Weak<Pool<T>>;
-            *ptr = Arc::downgrade(&pool);
+            let weak = &pool.weak_self as *const Weak<Pool<T>> as *mut Weak<Pool<T>>;
+            *weak = Arc::downgrade(&pool);
         }
         pool
     }
 
     pub fn get(&self) -> Item<T> {
+        // On a size-classed pool, route through the bucket path so the item is
+        // drawn from (and accounted against) the buckets, starting at the
+        // smallest class, rather than bypassing the bucket limits via the
+        // Treiber stack.
+        if self.buckets.is_some() {
+            return self.get_sized(0);
+        }
         self.get_impl(None).unwrap()
     }
 
+    /// # Panics
+    ///
+    /// Panics on a size-classed pool: the timeout/`Condvar` path is not
+    /// bucket-aware. Use [`get_sized`](Pool::get_sized) (or the non-blocking
+    /// [`try_get`](Pool::try_get)) instead.
     pub fn get_timeout(&self, duration: Duration) -> Result<Item<T>, TimeoutError> {
+        assert!(self.buckets.is_none(),
+            "get_timeout is not supported on size-classed pools; use get_sized");
         self.get_impl(Some(duration))
     }
 
+    /// Acquires an item without ever blocking on the `Condvar`.
+    ///
+    /// Returns `Some` if an item is available or the pool is under its
+    /// capacity (constructing a new one), and `None` if the pool is
+    /// saturated. Callers that want to shed load or fall back to a
+    /// non-pooled path can use this instead of the blocking
+    /// [`get`](Pool::get)/[`get_timeout`](Pool::get_timeout).
+    ///
+    /// On a size-classed pool this draws from (and accounts against) the
+    /// smallest class with room, spilling up like [`get`](Pool::get).
+    pub fn try_get(&self) -> Option<Item<T>> {
+        if self.buckets.is_some() {
+            return self.try_get_sized(0);
+        }
+        if let Some(item) = self.pop_valid() {
+            return Some(item);
+        }
+        if self.try_increment_count() {
+            return Some(self.wrap((self.constructor)(), Instant::now()));
+        }
+        None
+    }
+
+    /// Non-blocking form of [`get_sized`](Pool::get_sized): returns `None`
+    /// rather than waiting when every class from the best fit up is full.
+    fn try_get_sized(&self, min_len: usize) -> Option<Item<T>> {
+        let buckets_mutex = self.buckets.as_ref()
+            .expect("pool was not configured with size classes");
+        let mut buckets = buckets_mutex.lock().unwrap();
+
+        let start = buckets.iter().position(|bucket| bucket.block_size >= min_len)
+            .expect("no size class large enough for request");
+        for index in start..buckets.len() {
+            if let Some(value) = buckets[index].available.pop() {
+                return Some(self.wrap_sized(value, index));
+            }
+            if buckets[index].count < buckets[index].max {
+                buckets[index].count += 1;
+                self.count.fetch_add(1, Ordering::AcqRel);
+                let block_size = buckets[index].block_size;
+                drop(buckets);
+                let constructor = self.sized_constructor.as_ref().unwrap();
+                return Some(self.wrap_sized((**constructor)(block_size), index));
+            }
+        }
+        None
+    }
+
+    /// Acquires an item without blocking a worker thread.
+    ///
+    /// The returned future resolves as soon as an item is available or the
+    /// pool can construct a new one. While the pool is saturated the future
+    /// registers its [`Waker`] with the pool and returns `Pending`, so it can
+    /// be driven by an async runtime instead of parking on the `Condvar`.
+    ///
+    /// # Panics
+    ///
+    /// Panics on a size-classed pool: the async path is not bucket-aware. Use
+    /// [`get_sized`](Pool::get_sized) or [`try_get`](Pool::try_get) instead.
+    #[cfg(feature = "async")]
+    pub fn get_async(&self) -> impl Future<Output = Item<T>> {
+        assert!(self.buckets.is_none(),
+            "get_async is not supported on size-classed pools; use get_sized");
+        GetFuture { pool: self.weak_self.upgrade().unwrap() }
+    }
+
+    /// Yields a pooled item every time one becomes available.
+    ///
+    /// This is handy for pipelines that want to process a steady flow of
+    /// pooled resources; the stream never terminates.
+    ///
+    /// # Panics
+    ///
+    /// Panics on a size-classed pool, like [`get_async`](Pool::get_async).
+    #[cfg(feature = "async")]
+    pub fn stream(&self) -> impl futures_core::Stream<Item = Item<T>> {
+        assert!(self.buckets.is_none(),
+            "stream is not supported on size-classed pools; use get_sized");
+        PoolStream { pool: self.weak_self.upgrade().unwrap() }
+    }
+
     fn get_impl(&self, duration: Option<Duration>) -> Result<Item<T>, TimeoutError> {
-        let mut items = self.items.lock().unwrap();
+        let start = SystemTime::now();
+
+        loop {
+            if let Some(item) = self.pop_valid() {
+                return Ok(item);
+            }
 
-        if let Some(item) = items.available.pop() {
-            return Ok(self.wrap(item));
+            if self.try_increment_count() {
+                return Ok(self.wrap((self.constructor)(), Instant::now()));
+            }
+
+            // The fast path is exhausted and the pool is saturated; fall back
+            // to the mutex/condvar to wait for a returned item. Re-check under
+            // the lock so a `put` that raced the checks above is not missed.
+            let wait = self.wait.lock().unwrap();
+            // Register as a waiter before the re-check and before parking, so a
+            // concurrent `put` either pushed an item the re-check sees or sees
+            // us via `waiter_count` and takes the `wait` lock to wake us.
+            self.waiter_count.fetch_add(1, Ordering::SeqCst);
+            std::sync::atomic::fence(Ordering::SeqCst);
+            if !self.available_empty() || !self.at_capacity() {
+                self.waiter_count.fetch_sub(1, Ordering::AcqRel);
+                continue;
+            }
+            match duration {
+                Some(duration) => {
+                    let elapsed = start.elapsed().unwrap_or(Duration::from_secs(0));
+                    if elapsed >= duration {
+                        self.waiter_count.fetch_sub(1, Ordering::AcqRel);
+                        return Err(TimeoutError);
+                    }
+                    let guard = self.item_available.wait_timeout(wait, duration - elapsed).unwrap();
+                    self.waiter_count.fetch_sub(1, Ordering::AcqRel);
+                    drop(guard);
+                }
+                None => {
+                    let guard = self.item_available.wait(wait).unwrap();
+                    self.waiter_count.fetch_sub(1, Ordering::AcqRel);
+                    drop(guard);
+                }
+            }
         }
+    }
+
+    /// Pops the newest non-expired item that passes the `check` hook, dropping
+    /// (and decrementing `count` for) any expired or broken items it skips.
+    fn pop_valid(&self) -> Option<Item<T>> {
+        let now = Instant::now();
+        while let Some(node) = self.pop_node() {
+            let Node { pooled, .. } = *node;
+            if self.expired(&pooled, now) {
+                self.count.fetch_sub(1, Ordering::AcqRel);
+                continue;
+            }
+            if self.check.as_ref().map_or(true, |check| check(&pooled.value)) {
+                return Some(self.wrap(pooled.value, pooled.created));
+            }
+            self.count.fetch_sub(1, Ordering::AcqRel);
+        }
+        None
+    }
+
+    fn at_capacity(&self) -> bool {
+        self.count.load(Ordering::Acquire) >= self.max.unwrap_or(std::usize::MAX)
+    }
+
+    /// Claims a construction slot, bumping `count` iff the pool is under its
+    /// maximum. Returns `false` if the pool is already at capacity.
+    fn try_increment_count(&self) -> bool {
+        let max = self.max.unwrap_or(std::usize::MAX);
+        loop {
+            let count = self.count.load(Ordering::Acquire);
+            if count >= max {
+                return false;
+            }
+            if self.count
+                .compare_exchange_weak(count, count + 1, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok() {
+                return true;
+            }
+        }
+    }
 
-        if items.count < items.max.unwrap_or(std::usize::MAX) {
-            items.count += 1;
-            drop(items);
-            return Ok(self.wrap((self.constructor)()));
+    /// Pops the head node off the Treiber stack with a CAS loop.
+    ///
+    /// ABA-safe via the versioned head: each successful pop or push bumps the
+    /// tag in the high bits, so a pop that observed `head`/`next` cannot commit
+    /// after the head was reused at the same address — the tag no longer
+    /// matches and the compare-exchange retries.
+    fn pop_node(&self) -> Option<Box<Node<T>>> {
+        loop {
+            let current = self.available.load(Ordering::Acquire);
+            let head = (current & TREIBER_PTR_MASK) as *mut Node<T>;
+            if head.is_null() {
+                return None;
+            }
+            let next = unsafe { (*head).next } as usize;
+            let tag = current >> TREIBER_PTR_BITS;
+            let new = (tag.wrapping_add(1) << TREIBER_PTR_BITS) | next;
+            if self.available
+                .compare_exchange_weak(current, new, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok() {
+                self.available_count.fetch_sub(1, Ordering::AcqRel);
+                return Some(unsafe { Box::from_raw(head) });
+            }
         }
+    }
 
-        if duration.is_some() {
-            let duration = duration.unwrap();
-            let start = SystemTime::now();
-            while items.available.is_empty() {
-                let elapsed = start.elapsed().unwrap_or(Duration::from_secs(0));
-                if elapsed >= duration {
-                    return Err(TimeoutError);
+    /// Pushes a node onto the Treiber stack with a CAS loop, bumping the head's
+    /// version tag on success.
+    fn push_node(&self, mut node: Box<Node<T>>) {
+        loop {
+            let current = self.available.load(Ordering::Acquire);
+            node.next = (current & TREIBER_PTR_MASK) as *mut Node<T>;
+            let raw = Box::into_raw(node);
+            // The versioned head packs the address into the low TREIBER_PTR_BITS
+            // bits; an address wider than that (>48-bit VA: x86-64 5-level
+            // paging, aarch64 52-bit VA) would be silently truncated into the
+            // tag field and corrupt the stack. That is the hard platform limit
+            // of this packing scheme.
+            debug_assert_eq!(raw as usize >> TREIBER_PTR_BITS, 0,
+                "node address does not fit in TREIBER_PTR_BITS; unsupported on >48-bit VA platforms");
+            let tag = current >> TREIBER_PTR_BITS;
+            let new = (tag.wrapping_add(1) << TREIBER_PTR_BITS) | (raw as usize);
+            match self.available
+                .compare_exchange_weak(current, new, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => {
+                    self.available_count.fetch_add(1, Ordering::AcqRel);
+                    return;
                 }
-                items = self.item_available.wait_timeout(items, duration - elapsed).unwrap().0;
+                Err(_) => node = unsafe { Box::from_raw(raw) },
+            }
+        }
+    }
+
+    /// Returns `true` when the free list holds no nodes.
+    fn available_empty(&self) -> bool {
+        (self.available.load(Ordering::Acquire) & TREIBER_PTR_MASK) == 0
+    }
+
+    /// Number of idle items available for reuse, read without racing the
+    /// lock-free stack: the maintained `available_count` for a plain pool, or
+    /// the summed bucket lengths for a size-classed pool.
+    fn available_len(&self) -> usize {
+        if let Some(ref buckets) = self.buckets {
+            return buckets.lock().unwrap().iter().map(|bucket| bucket.available.len()).sum();
+        }
+        self.available_count.load(Ordering::Acquire)
+    }
+
+    fn expired(&self, pooled: &Pooled<T>, now: Instant) -> bool {
+        if let Some(max) = self.max_lifetime {
+            if now.duration_since(pooled.created) > max {
+                return true;
             }
-        } else {
-            while items.available.is_empty() {
-                items = self.item_available.wait(items).unwrap();
+        }
+        if let Some(max) = self.max_idle {
+            if now.duration_since(pooled.returned) > max {
+                return true;
             }
         }
+        false
+    }
 
-        Ok(self.wrap(items.available.pop().unwrap()))
+    /// Drops every available item that has exceeded its idle or lifetime
+    /// limit, shrinking `count` accordingly.
+    ///
+    /// This releases the memory or handles held by a burst-then-idle workload
+    /// instead of pinning `count` at its peak. It has no effect unless
+    /// `max_idle` or `max_lifetime` was configured.
+    pub fn reap(&self) {
+        if self.max_idle.is_none() && self.max_lifetime.is_none() {
+            return;
+        }
+        let now = Instant::now();
+        let mut kept = Vec::new();
+        while let Some(node) = self.pop_node() {
+            if self.expired(&node.pooled, now) {
+                self.count.fetch_sub(1, Ordering::AcqRel);
+            } else {
+                kept.push(node);
+            }
+        }
+        let repushed = !kept.is_empty();
+        // Re-push bottom-first so the kept items keep their newest-on-top order.
+        for node in kept.into_iter().rev() {
+            self.push_node(node);
+        }
+        if repushed && self.waiter_count.load(Ordering::Acquire) > 0 {
+            // A thread may have begun waiting while the scan left the stack
+            // empty; wake everyone now that the kept items are back.
+            let wait = self.wait.lock().unwrap();
+            #[cfg(feature = "async")]
+            {
+                let mut wait = wait;
+                while let Some(waker) = wait.waiters.pop_front() {
+                    self.waiter_count.fetch_sub(1, Ordering::AcqRel);
+                    waker.wake();
+                }
+                drop(wait);
+            }
+            #[cfg(not(feature = "async"))]
+            drop(wait);
+            self.item_available.notify_all();
+        }
+    }
+
+    /// Spawns a background thread that calls [`reap`](Pool::reap) every
+    /// `interval` until the pool is dropped.
+    #[cfg(feature = "reaper")]
+    pub fn spawn_reaper(self: &Arc<Pool<T>>, interval: Duration) -> thread::JoinHandle<()>
+        where T: Send + 'static {
+
+        let weak = Arc::downgrade(self);
+        thread::spawn(move || {
+            loop {
+                thread::sleep(interval);
+                match weak.upgrade() {
+                    Some(pool) => pool.reap(),
+                    None => break,
+                }
+            }
+        })
     }
 
-    fn wrap(&self, item: T) -> Item<T> {
+    fn wrap(&self, item: T, created: Instant) -> Item<T> {
+        self.record_acquire();
         Item {
             item: Some(item),
+            created,
+            bucket: None,
             pool: self.weak_self.upgrade().unwrap(),
         }
     }
 
-    fn put(&self, item: T) {
-        self.items.lock().unwrap().available.push(item);
+    fn wrap_sized(&self, item: T, bucket: usize) -> Item<T> {
+        self.record_acquire();
+        Item {
+            item: Some(item),
+            created: Instant::now(),
+            bucket: Some(bucket),
+            pool: self.weak_self.upgrade().unwrap(),
+        }
+    }
+
+    /// Bumps the outstanding-borrow and total-acquired counters, advancing the
+    /// high-water mark and firing the high watermark callback on a crossing.
+    fn record_acquire(&self) {
+        let outstanding = self.outstanding.fetch_add(1, Ordering::AcqRel) + 1;
+        self.high_water.fetch_max(outstanding, Ordering::AcqRel);
+        self.total_acquired.fetch_add(1, Ordering::AcqRel);
+        if let Some(ref watermarks) = self.watermarks {
+            if outstanding == watermarks.high {
+                (watermarks.callback)(Watermark::High, outstanding);
+            }
+        }
+    }
+
+    /// Mirror of [`record_acquire`](Pool::record_acquire) for a returned item.
+    fn record_release(&self) {
+        let outstanding = self.outstanding.fetch_sub(1, Ordering::AcqRel) - 1;
+        if let Some(ref watermarks) = self.watermarks {
+            if outstanding == watermarks.low {
+                (watermarks.callback)(Watermark::Low, outstanding);
+            }
+        }
+    }
+
+    fn put(&self, mut item: T, created: Instant) {
+        if let Some(ref recycle) = self.recycle {
+            recycle(&mut item);
+        }
+        self.record_release();
+        let pooled = Pooled { value: item, created, returned: Instant::now() };
+        self.push_node(Box::new(Node { pooled, next: ptr::null_mut() }));
+
+        // Uncontended hot path: with no parked waiter there is nothing to wake,
+        // so skip the `wait` mutex entirely and stay lock-free. A waiter that
+        // races this always registers in `waiter_count` and then re-checks the
+        // stack under the lock before parking (see `get_impl`/`poll`); with the
+        // fences on both sides it either sees the item we just pushed or is
+        // seen here and woken.
+        std::sync::atomic::fence(Ordering::SeqCst);
+        if self.waiter_count.load(Ordering::Acquire) > 0 {
+            self.wake_waiters();
+        }
+    }
+
+    /// Takes the `wait` lock and wakes one sync waiter and every registered
+    /// async waker. Only called when `waiter_count` is non-zero.
+    fn wake_waiters(&self) {
+        let wait = self.wait.lock().unwrap();
+        #[cfg(feature = "async")]
+        {
+            // Drain every registered waker rather than popping one: a waker left
+            // behind by a since-dropped future would otherwise consume this wake
+            // and starve a live waiter. Woken futures that lose the race simply
+            // re-register on their next poll.
+            let mut wait = wait;
+            while let Some(waker) = wait.waiters.pop_front() {
+                self.waiter_count.fetch_sub(1, Ordering::AcqRel);
+                waker.wake();
+            }
+            drop(wait);
+        }
+        #[cfg(not(feature = "async"))]
+        drop(wait);
         self.item_available.notify_one();
     }
+
+    fn put_sized(&self, mut item: T, bucket: usize) {
+        if let Some(ref recycle) = self.recycle {
+            recycle(&mut item);
+        }
+        self.record_release();
+        let buckets = self.buckets.as_ref().unwrap();
+        buckets.lock().unwrap()[bucket].available.push(item);
+        // Waiters may need different size classes; wake them all so the one
+        // that can use this bucket is not left parked.
+        self.bucket_available.notify_all();
+    }
+
+    /// Returns the number of idle items currently sitting in the free list.
+    pub fn len_available(&self) -> usize {
+        self.available_len()
+    }
+
+    /// Returns the total number of live items the pool has constructed and not
+    /// yet dropped (idle plus outstanding).
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Acquire)
+    }
+
+    /// Returns the pool's maximum capacity.
+    pub fn capacity(&self) -> usize {
+        self.max.unwrap_or(std::usize::MAX)
+    }
+
+    /// Returns a snapshot of the pool's current usage statistics.
+    pub fn stats(&self) -> Stats {
+        Stats {
+            available: self.available_len(),
+            count: self.count.load(Ordering::Acquire),
+            capacity: self.capacity(),
+            outstanding: self.outstanding.load(Ordering::Acquire),
+            high_water: self.high_water.load(Ordering::Acquire),
+            total_acquired: self.total_acquired.load(Ordering::Acquire),
+        }
+    }
+}
+
+/// A single size class in a size-classed pool.
+struct Bucket<T> {
+    block_size: usize,
+    available: Vec<T>,
+    count: usize,
+    max: usize,
+}
+
+/// Builds a [`Pool`] with optional validation and recycling hooks.
+pub struct Builder<T> {
+    constructor: Box<Fn() -> T + Send + Sync + 'static>,
+    check: Option<Box<Fn(&T) -> bool + Send + Sync + 'static>>,
+    recycle: Option<Box<Fn(&mut T) + Send + Sync + 'static>>,
+    max_idle: Option<Duration>,
+    max_lifetime: Option<Duration>,
+    watermarks: Option<Watermarks>,
+    capacity: usize,
+}
+
+impl<T> Builder<T> {
+    /// Sets the maximum number of live items the pool may hold.
+    pub fn capacity(mut self, capacity: usize) -> Builder<T> {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets how long an item may sit unused in the free list before it is
+    /// dropped instead of being handed out again.
+    pub fn max_idle(mut self, max_idle: Duration) -> Builder<T> {
+        self.max_idle = Some(max_idle);
+        self
+    }
+
+    /// Sets the maximum age, measured from construction, past which an item is
+    /// dropped rather than reused.
+    pub fn max_lifetime(mut self, max_lifetime: Duration) -> Builder<T> {
+        self.max_lifetime = Some(max_lifetime);
+        self
+    }
+
+    /// Sets a health check run on each item before it is handed out. An item
+    /// for which the check returns `false` is dropped and replaced.
+    pub fn check<F>(mut self, check: F) -> Builder<T>
+        where F: Fn(&T) -> bool + Send + Sync + 'static {
+
+        self.check = Some(Box::new(check));
+        self
+    }
+
+    /// Sets a hook run on each item as it is returned to the pool, resetting
+    /// it to a clean state for the next borrow.
+    pub fn recycle<F>(mut self, recycle: F) -> Builder<T>
+        where F: Fn(&mut T) + Send + Sync + 'static {
+
+        self.recycle = Some(Box::new(recycle));
+        self
+    }
+
+    /// Sets the low and high outstanding-borrow thresholds and a callback
+    /// invoked whenever the number of outstanding items crosses one of them.
+    ///
+    /// The callback receives the watermark that was crossed and the current
+    /// outstanding count, so operators can log or alarm on pool pressure.
+    pub fn watermarks<F>(mut self, low: usize, high: usize, callback: F) -> Builder<T>
+        where F: Fn(Watermark, usize) + Send + Sync + 'static {
+
+        self.watermarks = Some(Watermarks {
+            low,
+            high,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    /// Consumes the builder and returns the configured pool.
+    pub fn build(self) -> Arc<Pool<T>> {
+        Pool::from_builder(self)
+    }
+}
+
+/// A snapshot of a pool's usage statistics, returned by [`Pool::stats`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    /// Idle items currently in the free list.
+    pub available: usize,
+    /// Live items the pool has constructed and not dropped.
+    pub count: usize,
+    /// The pool's maximum capacity.
+    pub capacity: usize,
+    /// Items currently checked out.
+    pub outstanding: usize,
+    /// The maximum number of simultaneously outstanding items ever seen.
+    pub high_water: usize,
+    /// Total number of items ever handed out.
+    pub total_acquired: usize,
+}
+
+/// Identifies which watermark an outstanding-borrow count crossed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Watermark {
+    /// Outstanding borrows fell to the low threshold.
+    Low,
+    /// Outstanding borrows rose to the high threshold.
+    High,
+}
+
+struct Watermarks {
+    low: usize,
+    high: usize,
+    callback: Box<Fn(Watermark, usize) + Send + Sync + 'static>,
+}
+
+#[cfg(feature = "async")]
+struct GetFuture<T> {
+    pool: Arc<Pool<T>>,
+}
+
+#[cfg(feature = "async")]
+impl<T> Future for GetFuture<T> {
+    type Output = Item<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Item<T>> {
+        let pool = &self.pool;
+
+        if let Some(item) = pool.pop_valid() {
+            return Poll::Ready(item);
+        }
+
+        if pool.try_increment_count() {
+            return Poll::Ready(pool.wrap((pool.constructor)(), Instant::now()));
+        }
+
+        // Register in `waiter_count`, then re-check under the `wait` lock,
+        // mirroring `get_impl`: a `put` that raced the checks above either
+        // pushed an item the re-check sees, or sees the waiter and takes this
+        // same lock to wake it, so no wakeup is lost.
+        let mut wait = pool.wait.lock().unwrap();
+        pool.waiter_count.fetch_add(1, Ordering::SeqCst);
+        std::sync::atomic::fence(Ordering::SeqCst);
+        if let Some(item) = pool.pop_valid() {
+            pool.waiter_count.fetch_sub(1, Ordering::AcqRel);
+            return Poll::Ready(item);
+        }
+        if pool.try_increment_count() {
+            pool.waiter_count.fetch_sub(1, Ordering::AcqRel);
+            return Poll::Ready(pool.wrap((pool.constructor)(), Instant::now()));
+        }
+        wait.waiters.push_back(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+#[cfg(feature = "async")]
+struct PoolStream<T> {
+    pool: Arc<Pool<T>>,
+}
+
+#[cfg(feature = "async")]
+impl<T> futures_core::Stream for PoolStream<T> {
+    type Item = Item<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Item<T>>> {
+        let pool = &self.pool;
+
+        if let Some(item) = pool.pop_valid() {
+            return Poll::Ready(Some(item));
+        }
+
+        if pool.try_increment_count() {
+            return Poll::Ready(Some(pool.wrap((pool.constructor)(), Instant::now())));
+        }
+
+        // Register, then re-check under the `wait` lock; see `GetFuture`.
+        let mut wait = pool.wait.lock().unwrap();
+        pool.waiter_count.fetch_add(1, Ordering::SeqCst);
+        std::sync::atomic::fence(Ordering::SeqCst);
+        if let Some(item) = pool.pop_valid() {
+            pool.waiter_count.fetch_sub(1, Ordering::AcqRel);
+            return Poll::Ready(Some(item));
+        }
+        if pool.try_increment_count() {
+            pool.waiter_count.fetch_sub(1, Ordering::AcqRel);
+            return Poll::Ready(Some(pool.wrap((pool.constructor)(), Instant::now())));
+        }
+        wait.waiters.push_back(cx.waker().clone());
+        Poll::Pending
+    }
 }
 
 impl<T> Debug for Pool<T> {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         fmt.debug_struct("Pool")
-            .field("items", &*self.items.lock().unwrap())
+            .field("items", &ItemsDebug {
+                available: self.available_len(),
+                count: self.count.load(Ordering::Acquire),
+                max: self.max,
+            })
             .finish()
     }
 }
 
-struct Items<T> {
-    available: Vec<T>,
+impl<T> Drop for Pool<T> {
+    fn drop(&mut self) {
+        // Free any nodes still sitting on the Treiber stack.
+        while self.pop_node().is_some() {}
+    }
+}
+
+/// State guarded by the fallback `wait` mutex: only the async waker queue and
+/// the companion for `item_available`. The hot path lives in the lock-free
+/// Treiber stack instead.
+struct Wait {
+    #[cfg(feature = "async")]
+    waiters: VecDeque<Waker>,
+}
+
+impl Wait {
+    fn new() -> Wait {
+        Wait {
+            #[cfg(feature = "async")]
+            waiters: VecDeque::new(),
+        }
+    }
+}
+
+/// A node in the lock-free free list, owning one idle item and a raw `next`
+/// pointer to the node beneath it.
+struct Node<T> {
+    pooled: Pooled<T>,
+    next: *mut Node<T>,
+}
+
+/// An idle item in the free list, tagged with the timestamps used to enforce
+/// the pool's idle and lifetime limits.
+struct Pooled<T> {
+    value: T,
+    created: Instant,
+    returned: Instant,
+}
+
+/// Renders the pool's snapshot in the same shape the old `Mutex<Items>` did.
+struct ItemsDebug {
+    available: usize,
     count: usize,
     max: Option<usize>,
 }
 
-impl<T> Debug for Items<T> {
+impl Debug for ItemsDebug {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         fmt.debug_struct("Items")
-            .field("available", &self.available.len())
+            .field("available", &self.available)
             .field("count", &self.count)
             .field("max", &self.max)
             .finish()
@@ -140,6 +986,8 @@ impl<T> Debug for Items<T> {
 
 pub struct Item<T> {
     item: Option<T>,
+    created: Instant,
+    bucket: Option<usize>,
     pool: Arc<Pool<T>>,
 }
 
@@ -167,7 +1015,11 @@ impl<T> DerefMut for Item<T> {
 
 impl<T> Drop for Item<T> {
     fn drop(&mut self) {
-        self.pool.put(self.item.take().unwrap());
+        let item = self.item.take().unwrap();
+        match self.bucket {
+            Some(bucket) => self.pool.put_sized(item, bucket),
+            None => self.pool.put(item, self.created),
+        }
     }
 }
 
@@ -189,10 +1041,51 @@ impl Error for TimeoutError {
 #[cfg(test)]
 mod tests {
     use std::error::Error;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
     use std::time::{Duration, SystemTime};
     use std::thread;
     use super::*;
 
+    #[cfg(feature = "async")]
+    fn noop_waker() -> Waker {
+        use std::task::{RawWaker, RawWakerVTable};
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(ptr::null(), &VTABLE)
+        }
+        fn no_op(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn get_async_resolves_when_item_returned() {
+        use futures_core::Stream;
+
+        let pool = Pool::with_capacity(1, || 0);
+        let x = pool.get();
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut future = Box::pin(pool.get_async());
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+
+        drop(x);
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(item) => assert_eq!(*item, 0),
+            Poll::Pending => panic!("future did not resolve after item returned"),
+        }
+
+        // The same wake path drives the stream.
+        let y = pool.get();
+        let mut stream = Box::pin(pool.stream());
+        assert!(stream.as_mut().poll_next(&mut cx).is_pending());
+        drop(y);
+        assert!(stream.as_mut().poll_next(&mut cx).is_ready());
+    }
+
     trait AsMillis {
         fn as_millis(&self) -> u64;
     }
@@ -284,4 +1177,227 @@ mod tests {
         assert_eq!(format!("{}", TimeoutError), "wait timed out");
         assert_eq!(TimeoutError.description(), "wait timed out");
     }
+
+    #[test]
+    fn get_sized_best_fit() {
+        let pool = Pool::size_classed(vec![(16, 2), (64, 2)], |block| vec![0u8; block]);
+        assert_eq!(pool.get_sized(10).len(), 16);
+        assert_eq!(pool.get_sized(40).len(), 64);
+    }
+
+    #[test]
+    fn get_sized_spills_up_when_full() {
+        let pool = Pool::size_classed(vec![(16, 1), (64, 1)], |block| vec![0u8; block]);
+        let _x = pool.get_sized(1);
+        assert_eq!(pool.get_sized(1).len(), 64);
+    }
+
+    #[test]
+    fn get_sized_returns_to_bucket() {
+        let pool = Pool::size_classed(vec![(16, 1)], |block| vec![0u8; block]);
+        let x = pool.get_sized(1);
+        drop(x);
+        assert_eq!(pool.count(), 1);
+        assert_eq!(pool.get_sized(1).len(), 16);
+        assert_eq!(pool.count(), 1);
+    }
+
+    #[test]
+    fn sized_pool_reports_available_from_buckets() {
+        let pool = Pool::size_classed(vec![(16, 2), (64, 2)], |block| vec![0u8; block]);
+        let a = pool.get_sized(1);
+        let b = pool.get_sized(40);
+        assert_eq!(pool.len_available(), 0);
+        drop(a);
+        drop(b);
+        assert_eq!(pool.len_available(), 2);
+        assert_eq!(pool.stats().available, 2);
+    }
+
+    #[test]
+    fn get_sized_tracks_global_count() {
+        let pool = Pool::size_classed(vec![(16, 2)], |block| vec![0u8; block]);
+        let _x = pool.get_sized(1);
+        let _y = pool.get_sized(1);
+        assert_eq!(pool.count(), 2);
+        assert_eq!(pool.stats().count, 2);
+    }
+
+    #[test]
+    fn get_on_sized_pool_uses_buckets() {
+        let pool = Pool::size_classed(vec![(16, 2)], |block| vec![0u8; block]);
+        let x = pool.get();
+        assert_eq!(x.len(), 16);
+        assert_eq!(pool.count(), 1);
+    }
+
+    #[test]
+    fn try_get_on_sized_pool_respects_bucket_limit() {
+        let pool = Pool::size_classed(vec![(16, 1)], |block| vec![0u8; block]);
+        let x = pool.try_get().unwrap();
+        assert_eq!(x.len(), 16);
+        assert!(pool.try_get().is_none());
+        assert_eq!(pool.count(), 1);
+        drop(x);
+        assert!(pool.try_get().is_some());
+        assert_eq!(pool.count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "size-classed")]
+    fn get_timeout_on_sized_pool_panics() {
+        let pool = Pool::size_classed(vec![(16, 1)], |block| vec![0u8; block]);
+        let _ = pool.get_timeout(Duration::from_millis(1));
+    }
+
+    #[test]
+    fn max_idle_drops_expired_on_get() {
+        let pool = Pool::builder(|| 0).capacity(2)
+            .max_idle(Duration::from_millis(50)).build();
+        let x = pool.get();
+        drop(x);
+        assert_eq!(pool.count(), 1);
+        thread::sleep(Duration::from_millis(100));
+        let _y = pool.get();
+        assert_eq!(pool.count(), 1);
+        assert_eq!(pool.len_available(), 0);
+    }
+
+    #[test]
+    fn reap_drops_idle_items() {
+        let pool = Pool::builder(|| 0).capacity(4)
+            .max_idle(Duration::from_millis(50)).build();
+        let a = pool.get();
+        let b = pool.get();
+        drop(a);
+        drop(b);
+        assert_eq!(pool.count(), 2);
+        thread::sleep(Duration::from_millis(100));
+        pool.reap();
+        assert_eq!(pool.count(), 0);
+        assert_eq!(pool.len_available(), 0);
+    }
+
+    #[test]
+    fn treiber_stack_roundtrips() {
+        let pool = Pool::with_capacity(4, || 0);
+        let a = pool.get();
+        let b = pool.get();
+        drop(a);
+        drop(b);
+        assert_eq!(pool.len_available(), 2);
+        let _c = pool.get();
+        let _d = pool.get();
+        assert_eq!(pool.len_available(), 0);
+    }
+
+    #[test]
+    fn treiber_stack_concurrent_churn() {
+        let pool = Pool::with_capacity(8, || 0);
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let pool = pool.clone();
+            handles.push(thread::spawn(move || {
+                for _ in 0..10000 {
+                    let _x = pool.get();
+                }
+            }));
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(pool.count() <= 8);
+        assert_eq!(pool.len_available(), pool.count());
+    }
+
+    #[test]
+    fn try_get_none_when_saturated() {
+        let pool = Pool::with_capacity(1, || 0);
+        let _x = pool.try_get().unwrap();
+        assert!(pool.try_get().is_none());
+    }
+
+    #[test]
+    fn try_get_reuses_returned_item() {
+        let pool = Pool::with_capacity(1, || 0);
+        let x = pool.try_get().unwrap();
+        drop(x);
+        assert!(pool.try_get().is_some());
+    }
+
+    #[test]
+    fn with_prefilled_constructs_eagerly() {
+        let pool = Pool::with_prefilled(5, 3, || 0);
+        assert_eq!(pool.len_available(), 3);
+        assert_eq!(pool.count(), 3);
+    }
+
+    #[test]
+    fn with_prefilled_clamps_to_capacity() {
+        let pool = Pool::with_prefilled(4, 10, || 0);
+        assert_eq!(pool.capacity(), 4);
+        assert_eq!(pool.count(), 4);
+        assert_eq!(pool.len_available(), 4);
+    }
+
+    #[test]
+    fn check_false_drops_and_replaces() {
+        // `check` only runs on items popped from the free list. A returned
+        // item whose check fails is dropped on the next pop, which forces a
+        // fresh construction.
+        let pool = Pool::builder(|| 1).capacity(1).check(|&v| v == 2).build();
+        let x = pool.get();
+        assert_eq!(*x, 1);
+        drop(x);
+        assert_eq!(pool.len_available(), 1);
+        let y = pool.get();
+        assert_eq!(*y, 1);
+        assert_eq!(pool.len_available(), 0);
+        assert_eq!(pool.count(), 1);
+    }
+
+    #[test]
+    fn recycle_resets_returned_item() {
+        let pool = Pool::builder(|| 0).capacity(1).recycle(|v| *v = 0).build();
+        let mut x = pool.get();
+        *x = 42;
+        drop(x);
+        assert_eq!(*pool.get(), 0);
+    }
+
+    #[test]
+    fn stats_snapshot() {
+        let pool = Pool::with_capacity(4, || 0);
+        let a = pool.get();
+        let _b = pool.get();
+        drop(a);
+        let stats = pool.stats();
+        assert_eq!(stats.capacity, 4);
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.available, 1);
+        assert_eq!(stats.outstanding, 1);
+        assert_eq!(stats.high_water, 2);
+        assert_eq!(stats.total_acquired, 2);
+    }
+
+    #[test]
+    fn watermark_callbacks_fire() {
+        let high = Arc::new(AtomicUsize::new(0));
+        let low = Arc::new(AtomicUsize::new(0));
+        let high_hits = high.clone();
+        let low_hits = low.clone();
+        let pool = Pool::builder(|| 0).capacity(4)
+            .watermarks(0, 2, move |watermark, _| match watermark {
+                Watermark::High => { high_hits.fetch_add(1, Ordering::SeqCst); }
+                Watermark::Low => { low_hits.fetch_add(1, Ordering::SeqCst); }
+            })
+            .build();
+
+        let a = pool.get();
+        let b = pool.get();
+        assert_eq!(high.load(Ordering::SeqCst), 1);
+        drop(a);
+        drop(b);
+        assert_eq!(low.load(Ordering::SeqCst), 1);
+    }
 }